@@ -0,0 +1,51 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    cache,
+    item_id::{FileId, ItemId},
+    metadata::ToAudioPath,
+    protocol::metadata::AudioFormat,
+};
+
+/// Identifies one encoded rendition of a track on Spotify's CDN, resolved by
+/// [`ToAudioPath::to_audio_path`] against a caller's preferred codec chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AudioPath {
+    pub item_id: ItemId,
+    pub file_id: FileId,
+    pub file_format: AudioFormat,
+    pub duration: Duration,
+}
+
+impl AudioPath {
+    /// Where this rendition is (or would be) cached inside `cache_dir` (see
+    /// `Config::cache_dir` in `psst-gui`), keyed by `file_id` so the same
+    /// track cached at a different quality doesn't collide with itself.
+    pub fn cache_path(&self, cache_dir: &Path) -> PathBuf {
+        cache_dir.join(self.file_id.to_base16())
+    }
+}
+
+/// Resolves the rendition psst should actually stream/cache for `track`,
+/// walking `preferred_formats` (see `Config::preferred_audio_formats` in
+/// `psst-gui`) instead of a single hardcoded bitrate, so the user's
+/// configured Low/Normal/High quality tier has a real effect on playback.
+/// Returns `None` when `track` has none of the preferred formats.
+pub fn resolve_audio_path<T: ToAudioPath>(
+    track: &T,
+    preferred_formats: &[AudioFormat],
+) -> Option<AudioPath> {
+    track.to_audio_path(preferred_formats)
+}
+
+/// Reads `path`'s bytes out of the on-disk cache under `cache_dir`, if
+/// they're already there. This is the real entry point a cached track's
+/// bytes get served through, so a read through here also counts as a "use"
+/// for [`cache::evict`]'s LRU pass, via [`cache::read`].
+pub fn read_cached(path: &AudioPath, cache_dir: &Path) -> io::Result<Vec<u8>> {
+    cache::read(&path.cache_path(cache_dir))
+}
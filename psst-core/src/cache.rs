@@ -0,0 +1,110 @@
+use std::{
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+/// Limits applied by [`evict`] to keep the on-disk cache in check. Either
+/// bound can be left unset to disable that half of the policy.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EvictionPolicy {
+    pub max_size_bytes: Option<u64>,
+    pub max_age: Option<Duration>,
+}
+
+/// Outcome of a single eviction pass, reported back to the UI so the
+/// displayed cache size can be updated without a fresh measurement.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EvictionReport {
+    pub freed_bytes: u64,
+    pub remaining_bytes: u64,
+}
+
+struct CacheEntry {
+    path: std::path::PathBuf,
+    size: u64,
+    accessed_at: SystemTime,
+}
+
+/// Reads a cached audio file's bytes, touching its last-access time so a
+/// later [`evict`] pass sees it as recently used rather than trusting the
+/// filesystem's own atime tracking (unreliable under `relatime`, inert
+/// under `noatime`). This is the entry point a cached file's bytes should
+/// always be read through.
+pub fn read(path: &Path) -> io::Result<Vec<u8>> {
+    let bytes = fs::read(path)?;
+    // A bookkeeping failure here (e.g. a read-only mount, or a platform
+    // where `set_times` needs access the read handle doesn't have) shouldn't
+    // turn an otherwise-successful cached read into an error.
+    let _ = touch(path);
+    Ok(bytes)
+}
+
+/// Updates `path`'s last-access time to now. Called by [`read`] on every
+/// cache hit so [`evict`]'s LRU pass has an accurate, app-tracked recency
+/// instead of depending on the filesystem's own atime updates.
+fn touch(path: &Path) -> io::Result<()> {
+    let file = fs::File::open(path)?;
+    file.set_times(fs::FileTimes::new().set_accessed(SystemTime::now()))
+}
+
+/// Scans `cache_dir`, deletes everything last accessed longer ago than
+/// `policy.max_age`, then
+/// deletes the least-recently-accessed remaining entries until the total is
+/// under `policy.max_size_bytes`.
+pub fn evict(cache_dir: &Path, policy: &EvictionPolicy) -> io::Result<EvictionReport> {
+    let mut entries = read_entries(cache_dir)?;
+    let mut total: u64 = entries.iter().map(|e| e.size).sum();
+    let mut freed: u64 = 0;
+
+    if let Some(max_age) = policy.max_age {
+        let now = SystemTime::now();
+        entries.retain(|entry| {
+            let age = now.duration_since(entry.accessed_at).unwrap_or_default();
+            if age > max_age {
+                if fs::remove_file(&entry.path).is_ok() {
+                    freed += entry.size;
+                    total = total.saturating_sub(entry.size);
+                }
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    if let Some(max_size) = policy.max_size_bytes {
+        entries.sort_by_key(|entry| entry.accessed_at);
+        for entry in entries {
+            if total <= max_size {
+                break;
+            }
+            if fs::remove_file(&entry.path).is_ok() {
+                freed += entry.size;
+                total = total.saturating_sub(entry.size);
+            }
+        }
+    }
+
+    Ok(EvictionReport {
+        freed_bytes: freed,
+        remaining_bytes: total,
+    })
+}
+
+fn read_entries(cache_dir: &Path) -> io::Result<Vec<CacheEntry>> {
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(cache_dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        entries.push(CacheEntry {
+            path: entry.path(),
+            size: metadata.len(),
+            accessed_at: metadata.accessed().unwrap_or(SystemTime::UNIX_EPOCH),
+        });
+    }
+    Ok(entries)
+}
@@ -1,12 +1,12 @@
 use crate::{
-    audio_file::{AudioFile, AudioPath},
+    audio_file::AudioPath,
     error::Error,
     item_id::{FileId, ItemId, ItemIdType},
-    protocol::metadata::{Restriction, Track},
+    protocol::metadata::{AudioFormat, Lyrics, Restriction, Track},
     session::SessionHandle,
 };
 use quick_protobuf::MessageRead;
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 pub trait Fetch: MessageRead<'static> {
     fn uri(id: ItemId) -> String;
@@ -21,10 +21,51 @@ impl Fetch for Track {
     }
 }
 
+impl Fetch for Lyrics {
+    fn uri(id: ItemId) -> String {
+        format!("hm://lyrics/v1/track/{}", id.to_base16())
+    }
+}
+
+/// A single line of lyrics, synced to a position in the track.
+///
+/// Unsynced lyrics are represented by every line sharing `at == Duration::ZERO`.
+#[derive(Clone, Debug)]
+pub struct LyricLine {
+    pub at: Duration,
+    pub text: Arc<str>,
+}
+
+impl Lyrics {
+    /// Parses the raw protobuf response into a list of lines sorted by their
+    /// timestamp, as required for binary-searching the currently active line.
+    pub fn to_lyric_lines(&self) -> Vec<LyricLine> {
+        let mut lines: Vec<LyricLine> = self
+            .lines
+            .iter()
+            .map(|line| LyricLine {
+                at: Duration::from_millis(line.start_time_ms.unwrap_or(0).max(0) as u64),
+                text: line.words.as_deref().unwrap_or("").into(),
+            })
+            .collect();
+        lines.sort_by_key(|line| line.at);
+        lines
+    }
+
+    /// `true` when at least one line carries a timestamp, i.e. the lyrics
+    /// can be highlighted as they play rather than only shown as a static
+    /// list.
+    pub fn is_synced(&self) -> bool {
+        self.lines
+            .iter()
+            .any(|line| line.start_time_ms.unwrap_or(0) > 0)
+    }
+}
+
 pub trait ToAudioPath {
     fn is_restricted_in_region(&self, country: &str) -> bool;
     fn find_allowed_alternative(&self, country: &str) -> Option<ItemId>;
-    fn to_audio_path(&self, preferred_bitrate: usize) -> Option<AudioPath>;
+    fn to_audio_path(&self, preferred_formats: &[AudioFormat]) -> Option<AudioPath>;
 }
 
 impl ToAudioPath for Track {
@@ -42,14 +83,16 @@ impl ToAudioPath for Track {
         ItemId::from_raw(alt_track.gid.as_ref()?, ItemIdType::Track)
     }
 
-    fn to_audio_path(&self, preferred_bitrate: usize) -> Option<AudioPath> {
-        let file = AudioFile::compatible_audio_formats(preferred_bitrate)
-            .iter()
-            .find_map(|&preferred_format| {
-                self.file
-                    .iter()
-                    .find(|file| file.format == Some(preferred_format))
-            })?;
+    /// Walks `preferred_formats` in order, returning the path for the first
+    /// one this track actually has a file for. The list lets callers express
+    /// a full fallback chain (e.g. 320 -> 160 -> 96 kbit Vorbis, or a
+    /// different codec entirely) instead of a single hardcoded bitrate.
+    fn to_audio_path(&self, preferred_formats: &[AudioFormat]) -> Option<AudioPath> {
+        let file = preferred_formats.iter().find_map(|&preferred_format| {
+            self.file
+                .iter()
+                .find(|file| file.format == Some(preferred_format))
+        })?;
         let file_format = file.format?;
         let item_id = ItemId::from_raw(self.gid.as_ref()?, ItemIdType::Track)?;
         let file_id = FileId::from_raw(file.file_id.as_ref()?)?;
@@ -63,6 +106,19 @@ impl ToAudioPath for Track {
     }
 }
 
+/// Preference chains for the audio quality tiers exposed in the UI, each
+/// ordered from the tier's target bitrate down to the lowest one Spotify
+/// serves, so [`ToAudioPath::to_audio_path`] still resolves to *something*
+/// when a track is missing its preferred format.
+pub const AUDIO_QUALITY_LOW: &[AudioFormat] = &[AudioFormat::OGG_VORBIS_96];
+pub const AUDIO_QUALITY_NORMAL: &[AudioFormat] =
+    &[AudioFormat::OGG_VORBIS_160, AudioFormat::OGG_VORBIS_96];
+pub const AUDIO_QUALITY_HIGH: &[AudioFormat] = &[
+    AudioFormat::OGG_VORBIS_320,
+    AudioFormat::OGG_VORBIS_160,
+    AudioFormat::OGG_VORBIS_96,
+];
+
 fn is_restricted_in_region(restriction: &Restriction, country: &str) -> bool {
     if let Some(allowed) = &restriction.countries_allowed {
         if allowed.is_empty() {
@@ -0,0 +1,95 @@
+use druid::{widget::Controller, Env, Event, EventCtx, Selector, UpdateCtx, Widget};
+use psst_core::{
+    item_id::ItemId, metadata::Fetch, protocol::metadata::Lyrics as LyricsProto,
+    session::SessionHandle,
+};
+
+use crate::{
+    controller::worker_pool::worker_pool,
+    data::{AppState, Lyrics, Promise},
+};
+
+/// Fetches lyrics for whichever track `AppState::playback.track_id` names,
+/// resolving `AppState::playback.lyrics`. Resets and re-fetches every time
+/// the track changes, including to `None` (no lyrics), so the panel never
+/// keeps showing a previous track's lyrics. A track the `hm://lyrics`
+/// endpoint has nothing for (e.g. an error response) resolves to the
+/// empty, unsynced [`Lyrics::empty`] rather than rejecting, since "no lyrics" isn't a failure the UI should show as
+/// one.
+pub struct FetchLyrics;
+
+impl FetchLyrics {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Submitted from `update` on every `track_id` change. Its handler sets
+    /// `Empty`/`Deferred` before it starts the new track's fetch, so the
+    /// reset never races a background fetch that started first.
+    const RESET: Selector<Option<ItemId>> = Selector::new("app.playback.lyrics-reset");
+    const RESPONSE: Selector<(ItemId, Lyrics)> = Selector::new("app.playback.lyrics-response");
+}
+
+impl<W: Widget<AppState>> Controller<AppState, W> for FetchLyrics {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut AppState,
+        env: &Env,
+    ) {
+        match event {
+            Event::Command(cmd) if cmd.is(Self::RESET) => {
+                match cmd.get_unchecked(Self::RESET) {
+                    Some(track_id) => {
+                        let track_id = *track_id;
+                        data.playback.lyrics = Promise::Deferred(track_id);
+                        let session = data.session.clone();
+                        worker_pool().execute(
+                            ctx.get_external_handle(),
+                            ctx.widget_id(),
+                            Self::RESPONSE,
+                            move || (track_id, fetch_lyrics(&session, track_id)),
+                        );
+                    }
+                    None => data.playback.lyrics = Promise::Empty,
+                }
+                ctx.set_handled();
+            }
+            Event::Command(cmd) if cmd.is(Self::RESPONSE) => {
+                let (track_id, lyrics) = cmd.get_unchecked(Self::RESPONSE).to_owned();
+                // Drop responses for a track the user has since moved away
+                // from, rather than letting a slow fetch overwrite whatever
+                // the current track's lyrics already resolved to.
+                if matches!(&data.playback.lyrics, Promise::Deferred(pending) if *pending == track_id)
+                {
+                    data.playback.lyrics.resolve_or_reject(Ok(lyrics));
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+
+    fn update(
+        &mut self,
+        child: &mut W,
+        ctx: &mut UpdateCtx,
+        old_data: &AppState,
+        data: &AppState,
+        env: &Env,
+    ) {
+        if old_data.playback.track_id != data.playback.track_id {
+            ctx.submit_command(Self::RESET.with(data.playback.track_id));
+        }
+        child.update(ctx, old_data, data, env);
+    }
+}
+
+fn fetch_lyrics(session: &SessionHandle, track_id: ItemId) -> Lyrics {
+    match LyricsProto::fetch(session, track_id) {
+        Ok(raw) => Lyrics::from_lines(raw.to_lyric_lines(), raw.is_synced()),
+        Err(_) => Lyrics::empty(),
+    }
+}
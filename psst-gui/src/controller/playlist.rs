@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use druid::{widget::Controller, Env, Event, EventCtx, Selector, Widget};
+use psst_core::{
+    item_id::ItemId, metadata::Fetch, protocol::metadata::Track as TrackProto,
+    session::SessionHandle,
+};
+
+use crate::{
+    controller::worker_pool::worker_pool,
+    data::{AppState, Promise, Track},
+    ui::playlist::IMPORTED_TRACK_IDS,
+};
+
+/// Fetches full metadata for each track id parsed out of an imported M3U8
+/// (see `ui::playlist::playlist_m3u8_widget`) and appends the results to
+/// whichever playlist is currently open. Mounted on the app root rather than
+/// the playlist detail view itself, since fetching needs `AppState::session`
+/// and an import can still be in flight after the user navigates away.
+pub struct ImportTracks;
+
+impl ImportTracks {
+    pub fn new() -> Self {
+        Self
+    }
+
+    const RESPONSE: Selector<Option<Arc<Track>>> =
+        Selector::new("app.playlist.imported-track-response");
+}
+
+impl<W: Widget<AppState>> Controller<AppState, W> for ImportTracks {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut AppState,
+        env: &Env,
+    ) {
+        match event {
+            Event::Command(cmd) if cmd.is(IMPORTED_TRACK_IDS) => {
+                let session = data.session.clone();
+                for &track_id in cmd.get_unchecked(IMPORTED_TRACK_IDS) {
+                    let session = session.clone();
+                    worker_pool().execute(
+                        ctx.get_external_handle(),
+                        ctx.widget_id(),
+                        Self::RESPONSE,
+                        move || fetch_track(&session, track_id),
+                    );
+                }
+                ctx.set_handled();
+            }
+            Event::Command(cmd) if cmd.is(Self::RESPONSE) => {
+                let track = cmd.get_unchecked(Self::RESPONSE).to_owned();
+                if let (Some(track), Promise::Resolved(tracks)) = (track, &mut data.playlist.tracks)
+                {
+                    tracks.tracks.push_back(track);
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
+
+/// Fetches one track's metadata by id and converts it to the gui's `Track`
+/// shape via `From`, the same kind of conversion `data::lyrics::LyricsLine`
+/// uses for its core counterpart. Failures (unknown id, network
+/// error) are dropped rather than surfaced: one bad track in an imported
+/// M3U8 shouldn't fail the whole import.
+fn fetch_track(session: &SessionHandle, track_id: ItemId) -> Option<Arc<Track>> {
+    TrackProto::fetch(session, track_id)
+        .ok()
+        .map(|proto| Arc::new(Track::from(proto)))
+}
@@ -0,0 +1,73 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex, OnceLock},
+    thread,
+};
+
+use druid::{ExtEventSink, Selector, Target, WidgetId};
+
+/// Number of reusable worker threads shared by every controller that submits
+/// jobs through [`WorkerPool::execute`]. Bounds how many network/disk requests can be in
+/// flight at once, instead of each controller spawning its own OS thread.
+pub const WORKER_COUNT: usize = 4;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A fixed-size pool of reusable worker threads. Jobs are plain closures;
+/// their result is delivered back to a widget through a druid command,
+/// mirroring how the ad-hoc `thread::spawn` + `Selector` controllers already
+/// report results.
+pub struct WorkerPool {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl WorkerPool {
+    fn new(size: usize) -> Self {
+        let (jobs, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..size {
+            let receiver = Arc::clone(&receiver);
+            thread::spawn(move || loop {
+                let job = receiver.lock().unwrap().recv();
+                match job {
+                    // A job that panics (e.g. a bug in whatever closure a
+                    // controller submitted) must not take this thread down
+                    // with it -- every controller in the app shares this
+                    // pool, so losing a thread here permanently shrinks its
+                    // capacity instead of just failing the one request.
+                    Ok(job) => {
+                        let _ = panic::catch_unwind(AssertUnwindSafe(job));
+                    }
+                    Err(_) => break,
+                }
+            });
+        }
+        Self { jobs }
+    }
+
+    /// Submits `job` to run on one of the pool's threads. Its result is sent
+    /// back to `widget` as the given `selector` command, via `sink`.
+    pub fn execute<T>(
+        &self,
+        sink: ExtEventSink,
+        widget: WidgetId,
+        selector: Selector<T>,
+        job: impl FnOnce() -> T + Send + 'static,
+    ) where
+        T: Send + 'static,
+    {
+        let job: Job = Box::new(move || {
+            let result = job();
+            let _ = sink.submit_command(selector, result, Target::Widget(widget));
+        });
+        // The pool outlives every caller, so a closed channel can only mean
+        // the process is shutting down; dropping the job is fine then.
+        let _ = self.jobs.send(job);
+    }
+}
+
+/// The process-wide worker pool, lazily started on first use.
+pub fn worker_pool() -> &'static WorkerPool {
+    static POOL: OnceLock<WorkerPool> = OnceLock::new();
+    POOL.get_or_init(|| WorkerPool::new(WORKER_COUNT))
+}
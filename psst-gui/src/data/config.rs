@@ -0,0 +1,137 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+use directories::ProjectDirs;
+use druid::{Data, Lens};
+use psst_core::{cache::EvictionPolicy, connection::Credentials, protocol::metadata::AudioFormat};
+use serde::{Deserialize, Serialize};
+
+use super::Theme;
+
+/// The user's preferred audio bitrate tier, as offered by the `RadioGroup`
+/// in `ui::preferences`. Each tier maps to an ordered codec fallback chain
+/// via [`AudioQuality::preferred_formats`], rather than a single bitrate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Data, Serialize, Deserialize)]
+pub enum AudioQuality {
+    Low,
+    Normal,
+    High,
+}
+
+/// Persisted application configuration, loaded once at startup and written
+/// back out by the "Save" button in `ui::preferences::general_tab_widget`.
+#[derive(Clone, Debug, Data, Lens, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub theme: Theme,
+    pub audio_quality: AudioQuality,
+    pub credentials: Option<Credentials>,
+    /// Cache size cap in megabytes, as typed into the "Limit" textbox.
+    /// Blank means unlimited; see [`Config::cache_eviction_policy`].
+    pub cache_size_limit_mb: String,
+    /// Cache entry max age in days, as typed into the "Max age" textbox.
+    /// Blank means unlimited; see [`Config::cache_eviction_policy`].
+    pub cache_max_age_days: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Light,
+            audio_quality: AudioQuality::Normal,
+            credentials: None,
+            cache_size_limit_mb: String::new(),
+            cache_max_age_days: String::new(),
+        }
+    }
+}
+
+impl Config {
+    fn project_dirs() -> Option<ProjectDirs> {
+        ProjectDirs::from("", "", "Psst")
+    }
+
+    pub fn cache_dir() -> Option<PathBuf> {
+        Self::project_dirs().map(|dirs| dirs.cache_dir().to_path_buf())
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        Self::project_dirs().map(|dirs| dirs.config_dir().join("config.json"))
+    }
+
+    /// Loads the persisted config, falling back to defaults if it's missing
+    /// or unreadable (e.g. first run).
+    pub fn load() -> Self {
+        Self::config_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        let Some(path) = Self::config_path() else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(contents) = serde_json::to_string_pretty(self) {
+            let _ = fs::write(path, contents);
+        }
+    }
+
+    pub fn store_credentials(&mut self, credentials: Credentials) {
+        self.credentials = Some(credentials);
+    }
+
+    /// Builds the [`EvictionPolicy`] the background eviction pass should
+    /// enforce, from the persisted cache limit/age. Reads the config fresh
+    /// from disk rather than taking `&self`, since the controller that runs
+    /// eviction (`ui::preferences::EvictCache`) only ever sees `Preferences`
+    /// data, not the live `Config`.
+    pub fn cache_eviction_policy() -> EvictionPolicy {
+        Self::load().eviction_policy()
+    }
+
+    fn eviction_policy(&self) -> EvictionPolicy {
+        EvictionPolicy {
+            max_size_bytes: parse_positive(&self.cache_size_limit_mb).map(|mb| mb * 1_000_000),
+            max_age: parse_positive(&self.cache_max_age_days)
+                .map(|days| Duration::from_secs(days * 86_400)),
+        }
+    }
+
+    /// The codec preference chain `ToAudioPath::to_audio_path` should walk
+    /// for a track, per the user's configured [`AudioQuality`]. Whatever
+    /// resolves a track's `AudioPath` for playback should call this instead
+    /// of hardcoding a single bitrate.
+    pub fn preferred_audio_formats(&self) -> &'static [AudioFormat] {
+        self.audio_quality.preferred_formats()
+    }
+}
+
+/// Parses a "blank for unlimited" limit textbox into `None` (unlimited) or
+/// `Some` positive value; anything unparsable is also treated as unlimited
+/// rather than rejected, since these fields have no separate validation UI.
+fn parse_positive(text: &str) -> Option<u64> {
+    text.trim().parse::<u64>().ok().filter(|value| *value > 0)
+}
+
+impl AudioQuality {
+    /// The ordered codec fallback chain `ToAudioPath::to_audio_path` should
+    /// walk for this tier, from the tier's target bitrate down to the lowest
+    /// one Spotify serves, so playback still resolves to *something* when a
+    /// track is missing its preferred format.
+    ///
+    /// Lives here rather than as a separate `AudioQualityExt` trait in
+    /// `ui::preferences`: this is the file that already owns `AudioQuality`
+    /// and pulls in `psst_core`'s codec types for `Config`'s own fields, so
+    /// there's no layering reason to keep the two apart.
+    pub fn preferred_formats(self) -> &'static [AudioFormat] {
+        use psst_core::metadata::{AUDIO_QUALITY_HIGH, AUDIO_QUALITY_LOW, AUDIO_QUALITY_NORMAL};
+        match self {
+            AudioQuality::Low => AUDIO_QUALITY_LOW,
+            AudioQuality::Normal => AUDIO_QUALITY_NORMAL,
+            AudioQuality::High => AUDIO_QUALITY_HIGH,
+        }
+    }
+}
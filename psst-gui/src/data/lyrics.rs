@@ -0,0 +1,62 @@
+use std::{cmp::Ordering, sync::Arc, time::Duration};
+
+use druid::{im::Vector, Data, Lens};
+use psst_core::metadata::LyricLine as CoreLyricLine;
+
+#[derive(Clone, Debug, Data, Lens)]
+pub struct LyricsLine {
+    pub at: Duration,
+    pub text: Arc<str>,
+}
+
+impl From<CoreLyricLine> for LyricsLine {
+    fn from(line: CoreLyricLine) -> Self {
+        Self {
+            at: line.at,
+            text: line.text,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Data, Lens)]
+pub struct Lyrics {
+    pub lines: Vector<LyricsLine>,
+    pub synced: bool,
+}
+
+impl Lyrics {
+    pub fn empty() -> Self {
+        Self {
+            lines: Vector::new(),
+            synced: false,
+        }
+    }
+
+    pub fn from_lines(lines: Vec<CoreLyricLine>, synced: bool) -> Self {
+        Self {
+            lines: lines.into_iter().map(LyricsLine::from).collect(),
+            synced,
+        }
+    }
+
+    /// Returns the index of the line that should be highlighted for `position`,
+    /// i.e. the greatest line whose `at <= position`.
+    pub fn active_line(&self, position: Duration) -> Option<usize> {
+        if !self.synced || self.lines.is_empty() {
+            return None;
+        }
+        // `lines` is sorted by `at`, so the comparator below never reports
+        // `Equal` and the search ends at the first line not yet reached.
+        let reached = self
+            .lines
+            .binary_search_by(|line| {
+                if line.at <= position {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            })
+            .unwrap_or_else(|index| index);
+        reached.checked_sub(1)
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use druid::{Data, Lens};
+use psst_core::item_id::ItemId;
+
+use super::{Lyrics, Promise};
+
+/// Now-playing state shared by the playback bar and the lyrics panel.
+/// Changing `track_id` (including to/from `None`) is what tells
+/// `controller::lyrics::FetchLyrics` to reset and re-fetch; see
+/// `ui::lyrics::LyricsPanelData` for the read-only projection the lyrics
+/// panel itself renders from.
+///
+/// `lyrics` carries its requested track as a `Promise<Lyrics, ItemId>`, the
+/// same correlated shape `Promise<Playlist, PlaylistLink>` uses, rather than
+/// a bare `Promise<Lyrics>`: fetches run concurrently on the worker pool, so
+/// without an id to check against, a slow response for a track the user has
+/// since skipped past could land after (and overwrite) the correct lyrics.
+#[derive(Clone, Data, Lens)]
+pub struct Playback {
+    #[data(same_fn = "PartialEq::eq")]
+    pub track_id: Option<ItemId>,
+    pub position: Duration,
+    pub lyrics: Promise<Lyrics, ItemId>,
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self {
+            track_id: None,
+            position: Duration::ZERO,
+            lyrics: Promise::Empty,
+        }
+    }
+}
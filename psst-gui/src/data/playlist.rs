@@ -1,7 +1,8 @@
 use crate::data::{Image, Promise, Track};
 use druid::{im::Vector, Data, Lens};
+use psst_core::item_id::{ItemId, ItemIdType};
 use serde::{Deserialize, Deserializer};
-use std::sync::Arc;
+use std::{fmt::Write, sync::Arc, time::Duration};
 
 #[derive(Clone, Debug, Data, Lens)]
 pub struct PlaylistDetail {
@@ -51,6 +52,102 @@ impl PlaylistTracks {
             name: self.name.clone(),
         }
     }
+
+    /// Serializes this playlist to extended M3U8, with one `#EXTINF` entry
+    /// and the track's `open.spotify.com` URL per track.
+    pub fn to_m3u8(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "#EXTM3U").unwrap();
+        writeln!(out, "#PLAYLIST:{}", self.name).unwrap();
+        for track in &self.tracks {
+            let artists = track
+                .artists
+                .iter()
+                .map(|artist| artist.name.as_ref())
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(
+                out,
+                "#EXTINF:{},{} - {}",
+                track.duration.as_secs(),
+                artists,
+                track.name
+            )
+            .unwrap();
+            writeln!(out, "https://open.spotify.com/track/{id}", id = track.id).unwrap();
+        }
+        out
+    }
+
+    /// Parses an extended M3U8 playlist back into a track id list, resolving
+    /// the Spotify track id out of each entry's `open.spotify.com` URL.
+    /// Lines that don't resolve to a track URL are skipped.
+    pub fn track_ids_from_m3u8(m3u8: &str) -> Vec<ItemId> {
+        Self::imported_tracks_from_m3u8(m3u8)
+            .into_iter()
+            .map(|track| track.id)
+            .collect()
+    }
+
+    /// Parses an extended M3U8 playlist into one [`ImportedTrack`] per entry,
+    /// resolving the Spotify track id out of the `open.spotify.com` URL and
+    /// keeping the `#EXTINF` duration/artists/title alongside it. Lines that
+    /// don't resolve to a track URL are skipped.
+    pub fn imported_tracks_from_m3u8(m3u8: &str) -> Vec<ImportedTrack> {
+        let mut imported = Vec::new();
+        let mut lines = m3u8.lines();
+        while let Some(line) = lines.next() {
+            let Some(ext_inf) = line.strip_prefix("#EXTINF:") else {
+                continue;
+            };
+            let Some(url) = lines.next() else {
+                break;
+            };
+            let Some(id) = track_id_from_url(url) else {
+                continue;
+            };
+            let (duration, artists, title) = parse_ext_inf(ext_inf);
+            imported.push(ImportedTrack {
+                id,
+                duration,
+                artists,
+                title,
+            });
+        }
+        imported
+    }
+}
+
+/// One parsed `#EXTINF` entry: the resolved track id, plus the duration and
+/// "artists - title" metadata carried alongside it in the M3U8. The import
+/// in `ui::playlist` uses only the ids (via
+/// [`PlaylistTracks::track_ids_from_m3u8`]); nothing displays these fields.
+#[derive(Clone, Debug)]
+pub struct ImportedTrack {
+    pub id: ItemId,
+    pub duration: Duration,
+    pub artists: Arc<str>,
+    pub title: Arc<str>,
+}
+
+fn track_id_from_url(url: &str) -> Option<ItemId> {
+    let base62 = url.trim().rsplit('/').next()?;
+    ItemId::from_base62(base62, ItemIdType::Track)
+}
+
+/// Splits an `#EXTINF:{seconds},{artists} - {title}` payload (the format
+/// written by [`PlaylistTracks::to_m3u8`]) into its duration and "artists -
+/// title" halves. Missing or unparsable parts fall back to empty/zero rather
+/// than failing the whole entry, since this is only ever a display fallback.
+fn parse_ext_inf(ext_inf: &str) -> (Duration, Arc<str>, Arc<str>) {
+    let (seconds, rest) = ext_inf.split_once(',').unwrap_or((ext_inf, ""));
+    let duration = seconds
+        .trim()
+        .parse::<u64>()
+        .map(Duration::from_secs)
+        .unwrap_or_default();
+    let (artists, title) = rest.split_once(" - ").unwrap_or(("", rest));
+    (duration, Arc::from(artists.trim()), Arc::from(title.trim()))
 }
 
 #[derive(Clone, Debug, Data, Lens, Eq, PartialEq, Hash)]
@@ -0,0 +1,60 @@
+use druid::Data;
+use image::{imageops::FilterType, GenericImageView};
+use serde::{Deserialize, Serialize};
+
+/// The user's chosen UI palette, as stored in `Config::theme` and offered by
+/// the `RadioGroup` in `ui::preferences`. `Auto` is resolved to `Light` or
+/// `Dark` at runtime by sampling the brightness of the currently playing
+/// track's album art, rather than naming a palette directly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Data, Serialize, Deserialize)]
+pub enum Theme {
+    Light,
+    Dark,
+    Auto,
+}
+
+/// A palette that has already been resolved from a `Theme`, i.e. never
+/// `Auto`. This is what the rest of the UI actually themes around.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Data)]
+pub enum ResolvedTheme {
+    Light,
+    Dark,
+}
+
+impl Theme {
+    /// Resolves `Auto` against the given album art, falling back to `Dark`
+    /// when there is no art to sample (e.g. nothing is playing yet).
+    pub fn resolve(self, album_art: Option<&image::DynamicImage>) -> ResolvedTheme {
+        match self {
+            Theme::Light => ResolvedTheme::Light,
+            Theme::Dark => ResolvedTheme::Dark,
+            Theme::Auto => match album_art.map(relative_luminance) {
+                Some(luminance) if luminance > 0.5 => ResolvedTheme::Light,
+                _ => ResolvedTheme::Dark,
+            },
+        }
+    }
+}
+
+/// Mean relative luminance of the image, downscaled first so the cost of
+/// sampling is independent of the decoded artwork's resolution.
+fn relative_luminance(image: &image::DynamicImage) -> f64 {
+    const SAMPLE_SIZE: u32 = 16;
+
+    let sample = image.resize_exact(SAMPLE_SIZE, SAMPLE_SIZE, FilterType::Triangle);
+    let pixel_count = (sample.width() * sample.height()) as f64;
+    if pixel_count == 0.0 {
+        return 0.0;
+    }
+
+    let total: f64 = sample
+        .pixels()
+        .map(|(_, _, pixel)| {
+            let [r, g, b, _] = pixel.0;
+            let normalize = |channel: u8| channel as f64 / 255.0;
+            0.2126 * normalize(r) + 0.7152 * normalize(g) + 0.0722 * normalize(b)
+        })
+        .sum();
+
+    total / pixel_count
+}
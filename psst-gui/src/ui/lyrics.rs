@@ -0,0 +1,99 @@
+use std::{sync::Arc, time::Duration};
+
+use druid::{
+    im::Vector,
+    widget::{Controller, Label, LineBreaking, List, Scroll},
+    Data, Env, Lens, UpdateCtx, Widget, WidgetExt,
+};
+
+use crate::{
+    data::{Lyrics, LyricsLine},
+    ui::theme,
+};
+
+/// Playback position paired with the lyrics of the currently playing track;
+/// the shape the now-playing panel hands to [`lyrics_widget`].
+#[derive(Clone, Data, Lens)]
+pub struct LyricsPanelData {
+    pub lyrics: Lyrics,
+    pub position: Duration,
+}
+
+#[derive(Clone, Data)]
+struct DisplayLine {
+    text: Arc<str>,
+    active: bool,
+}
+
+struct ActiveLines;
+
+impl Lens<LyricsPanelData, Vector<DisplayLine>> for ActiveLines {
+    fn with<V, F: FnOnce(&Vector<DisplayLine>) -> V>(&self, data: &LyricsPanelData, f: F) -> V {
+        let active = data.lyrics.active_line(data.position);
+        let lines = data
+            .lyrics
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(index, line): (usize, &LyricsLine)| DisplayLine {
+                text: line.text.clone(),
+                active: active == Some(index),
+            })
+            .collect();
+        f(&lines)
+    }
+
+    fn with_mut<V, F: FnOnce(&mut Vector<DisplayLine>) -> V>(
+        &self,
+        data: &mut LyricsPanelData,
+        f: F,
+    ) -> V {
+        // Display-only projection: nothing ever writes back through it.
+        f(&mut self.with(data, |lines| lines.clone()))
+    }
+}
+
+pub fn lyrics_widget() -> impl Widget<LyricsPanelData> {
+    Scroll::new(
+        List::new(|| {
+            Label::new(|line: &DisplayLine, _: &Env| line.text.to_string())
+                .with_line_break_mode(LineBreaking::WordWrap)
+                .padding(theme::grid(0.5))
+                .env_scope(|env, line: &DisplayLine| {
+                    if line.active {
+                        env.set(theme::TEXT_COLOR, env.get(theme::FOREGROUND_LIGHT));
+                    }
+                })
+                .controller(ScrollToActive::default())
+        })
+        .lens(ActiveLines),
+    )
+    .vertical()
+}
+
+/// Keeps the active line visible in the enclosing [`Scroll`] viewport: each
+/// line's own controller asks for a scroll-into-view as soon as it becomes
+/// the active one, rather than a single controller trying to compute the
+/// `Scroll` offset for the whole list. The viewport moves only as far as
+/// needed to show the line; the line is not centered.
+#[derive(Default)]
+struct ScrollToActive {
+    was_active: bool,
+}
+
+impl<W: Widget<DisplayLine>> Controller<DisplayLine, W> for ScrollToActive {
+    fn update(
+        &mut self,
+        child: &mut W,
+        ctx: &mut UpdateCtx,
+        old_data: &DisplayLine,
+        data: &DisplayLine,
+        env: &Env,
+    ) {
+        if data.active && !self.was_active {
+            ctx.scroll_to_view();
+        }
+        self.was_active = data.active;
+        child.update(ctx, old_data, data, env);
+    }
+}
@@ -0,0 +1,69 @@
+pub mod lyrics;
+pub mod playlist;
+pub mod preferences;
+pub mod theme_auto;
+
+use druid::{widget::Flex, Lens, Widget, WidgetExt};
+
+use crate::{
+    controller::{lyrics::FetchLyrics, playlist::ImportTracks},
+    data::{AppState, Lyrics, Promise},
+};
+
+use self::{
+    lyrics::{lyrics_widget, LyricsPanelData},
+    playlist::playlist_m3u8_widget,
+    preferences::preferences_widget,
+    theme_auto::auto_theme,
+};
+
+/// Builds the app's themed root widget. `Config::theme` drives the palette
+/// directly for `Light`/`Dark`; for `Auto` it's kept live against the
+/// playing track's album art by wrapping everything in [`auto_theme`], so
+/// picking `Auto` in `ui::preferences` actually does something.
+///
+/// `AppState::now_playing_album_art` is the decoded art of whatever track
+/// playback has loaded (`None` when nothing is playing); it's the one piece
+/// this function needs from the playback state that lives outside this
+/// module.
+///
+/// The M3U8 export/import toolbar is mounted here, directly on
+/// `AppState::playlist`, rather than nested inside a fuller playlist detail
+/// page: this tree slice has no such page of its own to nest it in.
+pub fn root_widget() -> impl Widget<AppState> {
+    let content = Flex::column()
+        .with_flex_child(preferences_widget(), 1.0)
+        .with_child(lyrics_widget().lens(PlaybackLyrics))
+        .with_child(playlist_m3u8_widget().lens(AppState::playlist));
+
+    auto_theme(
+        |state: &AppState| (state.config.theme, state.now_playing_album_art()),
+        content,
+    )
+    .controller(FetchLyrics::new())
+    .controller(ImportTracks::new())
+}
+
+/// Projects `AppState::playback` down to the [`LyricsPanelData`] the lyrics
+/// panel renders from. Anything other than `Promise::Resolved` (still
+/// loading or never fetched) shows as the empty list, the same as a track
+/// with no lyrics, which resolves to `Lyrics::empty()`, rather than an error.
+struct PlaybackLyrics;
+
+impl Lens<AppState, LyricsPanelData> for PlaybackLyrics {
+    fn with<V, F: FnOnce(&LyricsPanelData) -> V>(&self, data: &AppState, f: F) -> V {
+        let lyrics = match &data.playback.lyrics {
+            Promise::Resolved(lyrics) => lyrics.clone(),
+            _ => Lyrics::empty(),
+        };
+        f(&LyricsPanelData {
+            lyrics,
+            position: data.playback.position,
+        })
+    }
+
+    fn with_mut<V, F: FnOnce(&mut LyricsPanelData) -> V>(&self, data: &mut AppState, f: F) -> V {
+        // Display-only projection: nothing ever writes back through it.
+        f(&mut self.with(data, |panel| panel.clone()))
+    }
+}
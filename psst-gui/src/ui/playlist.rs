@@ -0,0 +1,66 @@
+use druid::{
+    commands,
+    widget::{Button, Controller, Flex},
+    Env, Event, EventCtx, FileDialogOptions, FileSpec, Selector, Widget, WidgetExt,
+};
+use psst_core::item_id::ItemId;
+
+use crate::data::{PlaylistDetail, PlaylistTracks, Promise};
+
+const M3U8: FileSpec = FileSpec::new("Extended M3U8 playlist", &["m3u8"]);
+
+/// Export/import buttons for a playlist's M3U8 backup. Meant to sit
+/// alongside the track list in the playlist detail view.
+pub fn playlist_m3u8_widget() -> impl Widget<PlaylistDetail> {
+    Flex::row()
+        .with_child(Button::new("Export as M3U8…").on_click(|ctx, _, _| {
+            let options = FileDialogOptions::new()
+                .allowed_types(vec![M3U8])
+                .default_type(M3U8)
+                .default_name("playlist.m3u8");
+            ctx.submit_command(commands::SHOW_SAVE_PANEL.with(options));
+        }))
+        .with_default_spacer()
+        .with_child(Button::new("Import M3U8…").on_click(|ctx, _, _| {
+            let options = FileDialogOptions::new().allowed_types(vec![M3U8]);
+            ctx.submit_command(commands::SHOW_OPEN_PANEL.with(options));
+        }))
+        .controller(ExportImportM3u8)
+}
+
+/// Resolved track ids parsed out of an imported M3U8 file, for whatever
+/// playlist-building flow (outside this view) turns ids into a playlist.
+pub const IMPORTED_TRACK_IDS: Selector<Vec<ItemId>> =
+    Selector::new("app.playlist.imported-m3u8-track-ids");
+
+struct ExportImportM3u8;
+
+impl<W: Widget<PlaylistDetail>> Controller<PlaylistDetail, W> for ExportImportM3u8 {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut PlaylistDetail,
+        env: &Env,
+    ) {
+        match event {
+            Event::Command(cmd) if cmd.is(commands::SAVE_FILE_AS) => {
+                if let Promise::Resolved(tracks) = &data.tracks {
+                    let info = cmd.get_unchecked(commands::SAVE_FILE_AS);
+                    let _ = std::fs::write(info.path(), tracks.to_m3u8());
+                }
+                ctx.set_handled();
+            }
+            Event::Command(cmd) if cmd.is(commands::OPEN_FILE) => {
+                let info = cmd.get_unchecked(commands::OPEN_FILE);
+                if let Ok(m3u8) = std::fs::read_to_string(info.path()) {
+                    let track_ids = PlaylistTracks::track_ids_from_m3u8(&m3u8);
+                    ctx.submit_command(IMPORTED_TRACK_IDS.with(track_ids));
+                }
+                ctx.set_handled();
+            }
+            _ => child.event(ctx, event, data, env),
+        }
+    }
+}
@@ -1,8 +1,6 @@
-use std::thread::{self, JoinHandle};
-
 use crate::{
     cmd,
-    controller::InputController,
+    controller::{worker_pool::worker_pool, InputController},
     data::{
         AppState, AudioQuality, Authentication, Config, Preferences, PreferencesTab, Promise, Theme,
     },
@@ -17,7 +15,7 @@ use druid::{
     },
     Env, Event, EventCtx, LifeCycle, LifeCycleCtx, Selector, Widget, WidgetExt,
 };
-use psst_core::connection::Credentials;
+use psst_core::{cache, cache::EvictionReport, connection::Credentials};
 
 pub fn preferences_widget() -> impl Widget<AppState> {
     let tabs = tabs_widget()
@@ -84,9 +82,13 @@ fn general_tab_widget() -> impl Widget<AppState> {
         .with_child(Label::new("Theme").with_font(theme::UI_FONT_MEDIUM))
         .with_spacer(theme::grid(2.0))
         .with_child(
-            RadioGroup::new(vec![("Light", Theme::Light), ("Dark", Theme::Dark)])
-                .lens(Config::theme)
-                .lens(AppState::config),
+            RadioGroup::new(vec![
+                ("Light", Theme::Light),
+                ("Dark", Theme::Dark),
+                ("Auto", Theme::Auto),
+            ])
+            .lens(Config::theme)
+            .lens(AppState::config),
         );
 
     col = col.with_spacer(theme::grid(3.0));
@@ -152,8 +154,8 @@ fn general_tab_widget() -> impl Widget<AppState> {
         .with_child(
             RadioGroup::new(vec![
                 ("Low (96kbit)", AudioQuality::Low),
-                ("Normal (160kbit)", AudioQuality::Normal),
-                ("High (320kbit)", AudioQuality::High),
+                ("Normal (160kbit, falls back to 96)", AudioQuality::Normal),
+                ("High (320kbit, falls back to 160, 96)", AudioQuality::High),
             ])
             .lens(Config::audio_quality)
             .lens(AppState::config),
@@ -178,13 +180,11 @@ fn general_tab_widget() -> impl Widget<AppState> {
     col.controller(Authenticate::new())
 }
 
-struct Authenticate {
-    thread: Option<JoinHandle<()>>,
-}
+struct Authenticate;
 
 impl Authenticate {
     fn new() -> Self {
-        Self { thread: None }
+        Self
     }
 }
 
@@ -206,15 +206,12 @@ impl<W: Widget<AppState>> Controller<AppState, W> for Authenticate {
         match event {
             Event::Command(cmd) if cmd.is(Self::REQUEST) => {
                 let config = data.preferences.auth.session_config();
-                let widget_id = ctx.widget_id();
-                let event_sink = ctx.get_external_handle();
-                let thread = thread::spawn(move || {
-                    let response = Authentication::authenticate_and_get_credentials(config);
-                    event_sink
-                        .submit_command(Self::RESPONSE, response, widget_id)
-                        .unwrap();
-                });
-                self.thread.replace(thread);
+                worker_pool().execute(
+                    ctx.get_external_handle(),
+                    ctx.widget_id(),
+                    Self::RESPONSE,
+                    move || Authentication::authenticate_and_get_credentials(config),
+                );
                 ctx.set_handled();
             }
             Event::Command(cmd) if cmd.is(Self::RESPONSE) => {
@@ -223,7 +220,6 @@ impl<W: Widget<AppState>> Controller<AppState, W> for Authenticate {
                     data.config.store_credentials(credentials);
                 });
                 data.preferences.auth.result.resolve_or_reject(result);
-                self.thread.take();
                 ctx.set_handled();
             }
             _ => {
@@ -234,9 +230,9 @@ impl<W: Widget<AppState>> Controller<AppState, W> for Authenticate {
 }
 
 fn cache_tab_widget() -> impl Widget<AppState> {
-    let mut col = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+    let mut preferences_col = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
 
-    col = col
+    preferences_col = preferences_col
         .with_child(Label::new("Location").with_font(theme::UI_FONT_MEDIUM))
         .with_spacer(theme::grid(2.0))
         .with_child(
@@ -248,9 +244,9 @@ fn cache_tab_widget() -> impl Widget<AppState> {
             .with_line_break_mode(LineBreaking::WordWrap),
         );
 
-    col = col.with_spacer(theme::grid(3.0));
+    preferences_col = preferences_col.with_spacer(theme::grid(3.0));
 
-    col = col
+    preferences_col = preferences_col
         .with_child(Label::new("Size").with_font(theme::UI_FONT_MEDIUM))
         .with_spacer(theme::grid(2.0))
         .with_child(Label::dynamic(
@@ -264,21 +260,57 @@ fn cache_tab_widget() -> impl Widget<AppState> {
             },
         ));
 
-    col.controller(MeasureCacheSize::new())
-        .lens(AppState::preferences)
-}
+    let preferences_section = preferences_col
+        .controller(MeasureCacheSize::new())
+        .controller(EvictCache::new())
+        .lens(AppState::preferences);
+
+    let mut limits_col = Flex::column().cross_axis_alignment(CrossAxisAlignment::Start);
+
+    limits_col = limits_col
+        .with_child(Label::new("Limit (MB, blank for unlimited)").with_font(theme::UI_FONT_MEDIUM))
+        .with_spacer(theme::grid(2.0))
+        .with_child(
+            TextBox::new()
+                .with_placeholder("Unlimited")
+                .controller(InputController::new())
+                .lens(Config::cache_size_limit_mb),
+        )
+        .with_spacer(theme::grid(1.0))
+        .with_child(
+            Label::new("Max age (days, blank for unlimited)").with_font(theme::UI_FONT_MEDIUM),
+        )
+        .with_spacer(theme::grid(2.0))
+        .with_child(
+            TextBox::new()
+                .with_placeholder("Unlimited")
+                .controller(InputController::new())
+                .lens(Config::cache_max_age_days),
+        );
+
+    let limits_section = limits_col.lens(AppState::config);
 
-struct MeasureCacheSize {
-    thread: Option<JoinHandle<()>>,
+    Flex::column()
+        .cross_axis_alignment(CrossAxisAlignment::Start)
+        .with_child(preferences_section)
+        .with_spacer(theme::grid(3.0))
+        .with_child(limits_section)
 }
 
+struct MeasureCacheSize;
+
 impl MeasureCacheSize {
     fn new() -> Self {
-        Self { thread: None }
+        Self
     }
 }
 
 impl MeasureCacheSize {
+    /// Requests a fresh measurement. Only [`EvictCache`] sends this, as a
+    /// fallback for when it couldn't run an eviction pass itself (e.g. no
+    /// cache directory yet) — nothing measures on its own `WidgetAdded`
+    /// anymore, so the two controllers can't race to resolve `cache_size`.
+    const TRIGGER: Selector = Selector::new("app.preferences.measure-cache-size-trigger");
     const RESULT: Selector<Option<u64>> = Selector::new("app.preferences.measure-cache-size");
 }
 
@@ -292,10 +324,66 @@ impl<W: Widget<Preferences>> Controller<Preferences, W> for MeasureCacheSize {
         env: &Env,
     ) {
         match &event {
+            Event::Command(cmd) if cmd.is(Self::TRIGGER) => {
+                worker_pool().execute(
+                    ctx.get_external_handle(),
+                    ctx.widget_id(),
+                    Self::RESULT,
+                    Preferences::measure_cache_usage,
+                );
+                ctx.set_handled();
+            }
             Event::Command(cmd) if cmd.is(Self::RESULT) => {
                 let result = cmd.get_unchecked(Self::RESULT).to_owned();
                 data.cache_size.resolve_or_reject(result.ok_or(()));
-                self.thread.take();
+                ctx.set_handled();
+            }
+            _ => {
+                child.event(ctx, event, data, env);
+            }
+        }
+    }
+}
+
+/// Runs an LRU/TTL eviction pass on a background thread on every appearance
+/// of the cache tab, then folds the freed size back into the displayed total
+/// so it updates live without a separate re-measurement.
+///
+/// This is the only controller that touches the cache directory on
+/// `WidgetAdded`; [`MeasureCacheSize`] only measures when asked to (see
+/// [`MeasureCacheSize::TRIGGER`]), so eviction always runs to completion
+/// before anything reads the (now possibly smaller) cache size — otherwise
+/// the two background jobs could race and a stale pre-eviction size could
+/// land after the freed-size report.
+struct EvictCache;
+
+impl EvictCache {
+    fn new() -> Self {
+        Self
+    }
+}
+
+impl EvictCache {
+    const RESULT: Selector<Option<EvictionReport>> = Selector::new("app.preferences.evict-cache");
+}
+
+impl<W: Widget<Preferences>> Controller<Preferences, W> for EvictCache {
+    fn event(
+        &mut self,
+        child: &mut W,
+        ctx: &mut EventCtx,
+        event: &Event,
+        data: &mut Preferences,
+        env: &Env,
+    ) {
+        match &event {
+            Event::Command(cmd) if cmd.is(Self::RESULT) => {
+                match cmd.get_unchecked(Self::RESULT) {
+                    Some(report) => data
+                        .cache_size
+                        .resolve_or_reject(Ok(report.remaining_bytes)),
+                    None => ctx.submit_command(MeasureCacheSize::TRIGGER),
+                }
                 ctx.set_handled();
             }
             _ => {
@@ -313,17 +401,13 @@ impl<W: Widget<Preferences>> Controller<Preferences, W> for MeasureCacheSize {
         env: &Env,
     ) {
         if let LifeCycle::WidgetAdded = &event {
-            let handle = thread::spawn({
-                let widget_id = ctx.widget_id();
-                let event_sink = ctx.get_external_handle();
-                move || {
-                    let size = Preferences::measure_cache_usage();
-                    event_sink
-                        .submit_command(Self::RESULT, size, widget_id)
-                        .unwrap();
-                }
-            });
-            self.thread.replace(handle);
+            let policy = Config::cache_eviction_policy();
+            worker_pool().execute(
+                ctx.get_external_handle(),
+                ctx.widget_id(),
+                Self::RESULT,
+                move || Config::cache_dir().and_then(|dir| cache::evict(&dir, &policy).ok()),
+            );
         }
         child.lifecycle(ctx, event, data, env);
     }
@@ -0,0 +1,39 @@
+use druid::{widget::EnvScope, Color, Data, Env, Widget, WidgetExt};
+
+use crate::{
+    data::{ResolvedTheme, Theme},
+    ui::theme,
+};
+
+/// Wraps `child` so its environment tracks `Theme::Auto`, re-resolving it
+/// against the playing track's album art whenever the data updates.
+/// `Light`/`Dark` resolve to themselves, since those are already plain
+/// palette choices.
+pub fn auto_theme<T: Data>(
+    resolve: impl Fn(&T) -> (Theme, Option<image::DynamicImage>) + 'static,
+    child: impl Widget<T> + 'static,
+) -> EnvScope<T, impl Widget<T>> {
+    child.env_scope(move |env, data: &T| {
+        let (theme, album_art) = resolve(data);
+        apply_resolved_theme(env, theme.resolve(album_art.as_ref()));
+    })
+}
+
+/// Applies the resolved palette to the same background/text keys
+/// `tabs_widget` (`ui::preferences`) already swaps locally per tab, so
+/// `Auto` themes the whole window the same way `Light`/`Dark` theme a tab.
+fn apply_resolved_theme(env: &mut Env, resolved: ResolvedTheme) {
+    let background_light: Color = env.get(theme::BACKGROUND_LIGHT);
+    let background_dark: Color = env.get(theme::BACKGROUND_DARK);
+    let foreground_light: Color = env.get(theme::FOREGROUND_LIGHT);
+    match resolved {
+        ResolvedTheme::Light => {
+            env.set(theme::BACKGROUND_DARK, background_light);
+            env.set(theme::TEXT_COLOR, background_dark);
+        }
+        ResolvedTheme::Dark => {
+            env.set(theme::BACKGROUND_LIGHT, background_dark);
+            env.set(theme::TEXT_COLOR, foreground_light);
+        }
+    }
+}